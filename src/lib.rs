@@ -22,19 +22,19 @@
 //! primitive are:
 //!
 //!  - **Increased memory use**: since we keep two copies of the backing data structure, we are
-//!  effectively doubling the memory use of the underlying data. With some clever de-duplication,
-//!  this cost can be ameliorated to some degree, but it's something to be aware of. Furthermore,
-//!  if writers only call `publish` infrequently despite adding many writes to the operational log,
-//!  the operational log itself may grow quite large, which adds additional overhead.
+//!    effectively doubling the memory use of the underlying data. With some clever de-duplication,
+//!    this cost can be ameliorated to some degree, but it's something to be aware of. Furthermore,
+//!    if writers only call `publish` infrequently despite adding many writes to the operational log,
+//!    the operational log itself may grow quite large, which adds additional overhead.
 //!  - **Deterministic operations**: as the entries in the operational log are applied twice, once
-//!  to each copy of the data, it is essential that the operations are deterministic. If they are
-//!  not, the two copies will no longer mirror one another, and will continue to diverge over time.
+//!    to each copy of the data, it is essential that the operations are deterministic. If they are
+//!    not, the two copies will no longer mirror one another, and will continue to diverge over time.
 //!  - **Single writer**: left-right only supports a single writer. To have multiple writers, you
-//!  need to ensure exclusive access to the [`WriteHandle`] through something like a
-//!  [`Mutex`](std::sync::Mutex).
+//!    need to ensure exclusive access to the [`WriteHandle`] through something like a
+//!    [`Mutex`](std::sync::Mutex).
 //!  - **Slow writes**: Writes through left-right are slower than they would be directly against
-//!  the backing datastructure. This is both because they have to go through the operational log,
-//!  and because they must each be applied twice.
+//!    the backing datastructure. This is both because they have to go through the operational log,
+//!    and because they must each be applied twice.
 //!
 //! # How does it work?
 //!
@@ -176,16 +176,40 @@
 
 mod sync;
 
-use crate::sync::{Arc, AtomicUsize, Mutex};
+use crate::sync::{Arc, AtomicUsize, CachePadded, Mutex};
 
-type Epochs = Arc<Mutex<slab::Slab<Arc<AtomicUsize>>>>;
+// a reader's epoch, along with the label of the handle it belongs to (if any).
+//
+// the counter lives on its own cache line so that readers do not contend with each other when they
+// bump their epochs.
+#[derive(Debug)]
+struct Epoch {
+    counter: CachePadded<AtomicUsize>,
+    label: Option<String>,
+}
+
+impl Epoch {
+    fn new(label: Option<String>) -> Self {
+        Self {
+            counter: CachePadded::new(AtomicUsize::new(0)),
+            label,
+        }
+    }
+}
+
+type Epochs = Arc<Mutex<slab::Slab<Arc<Epoch>>>>;
 
 mod write;
+pub use crate::write::AutoPublish;
+pub use crate::write::Batch;
+pub use crate::write::BlockingReader;
+pub use crate::write::Poisoned;
 pub use crate::write::Taken;
+pub use crate::write::WaitStrategy;
 pub use crate::write::WriteHandle;
 
 mod read;
-pub use crate::read::{ReadGuard, ReadHandle, ReadHandleFactory};
+pub use crate::read::{OwnedReadGuard, ReadGuard, ReadHandle, ReadHandleFactory, WeakReadHandle};
 
 pub mod aliasing;
 
@@ -212,6 +236,29 @@ pub mod aliasing;
 /// the first of the two copies. In this case, de-duplicating implementations may need to forget
 /// values rather than drop them so that they are not dropped twice when the second copy is
 /// dropped.
+///
+/// # Side effects
+///
+/// Since every operation is absorbed twice, any side effects of absorbing it (like reporting
+/// whether a removed value was present) would naively happen twice as well. Each operation is
+/// handed to `absorb_first` at most once, and to `absorb_second` at most once. Operations appended
+/// before the first call to [`WriteHandle::publish`] skip `absorb_first` altogether. Every
+/// operation reaches `absorb_second` unless a publish panics and poisons the `WriteHandle`: the
+/// operations that have not yet been absorbed into both copies are then discarded by
+/// [`WriteHandle::recover`], or dropped along with the handle, so any side effects they still
+/// carry are lost. To perform a side effect exactly once, and as early as possible,
+/// store what is needed for it in the operation as an `Option` (say, the sending half of a
+/// channel), and `take` it in whichever of the two methods sees the operation first. The same
+/// holds for the batched variants of the two methods, which see each operation in place of them.
+///
+/// # Batching
+///
+/// During a publish, consecutive operations are handed over in batches through
+/// [`absorb_first_batch`](Absorb::absorb_first_batch) and
+/// [`absorb_second_batch`](Absorb::absorb_second_batch). By default, these just absorb each
+/// operation in turn, but implementations can override them to amortize work across operations,
+/// like looking up the same key only once for a run of operations that all touch it. Note that
+/// the operations of a single publish may be split across several batches.
 pub trait Absorb<O> {
     /// Apply `O` to the first of the two copies.
     ///
@@ -237,6 +284,35 @@ pub trait Absorb<O> {
         Self::absorb_first(self, &mut operation, other)
     }
 
+    /// Apply a batch of consecutive operations to the first of the two copies.
+    ///
+    /// This must have the same effect as calling `absorb_first` with each operation in turn, which
+    /// is what it defaults to. Implementations should exhaust `operations`. Any operations left in
+    /// it are passed to `absorb_first` one at a time after this method returns.
+    fn absorb_first_batch<'a, I>(&mut self, operations: I, other: &Self)
+    where
+        I: Iterator<Item = &'a mut O>,
+        O: 'a,
+    {
+        for operation in operations {
+            Self::absorb_first(self, operation, other);
+        }
+    }
+
+    /// Apply a batch of consecutive operations to the second of the two copies.
+    ///
+    /// This must have the same effect as calling `absorb_second` with each operation in turn,
+    /// which is what it defaults to. Implementations should exhaust `operations`. Any operations
+    /// left in it are passed to `absorb_second` one at a time after this method returns.
+    fn absorb_second_batch<I>(&mut self, operations: I, other: &Self)
+    where
+        I: Iterator<Item = O>,
+    {
+        for operation in operations {
+            Self::absorb_second(self, operation, other);
+        }
+    }
+
     /// Drop the first of the two copies.
     ///
     /// Defaults to calling `Self::drop`.
@@ -1,4 +1,4 @@
-use crate::sync::{fence, Arc, AtomicPtr, AtomicUsize, Ordering};
+use crate::sync::{self, fence, Arc, AtomicPtr, Ordering};
 use std::cell::Cell;
 use std::fmt;
 use std::marker::PhantomData;
@@ -9,11 +9,15 @@ use std::ptr::NonNull;
 use crate::WriteHandle;
 
 mod guard;
-pub use guard::ReadGuard;
+pub(crate) use guard::is_held;
+pub use guard::{OwnedReadGuard, ReadGuard};
 
 mod factory;
 pub use factory::ReadHandleFactory;
 
+mod weak;
+pub use weak::WeakReadHandle;
+
 /// A read handle to a left-right guarded data structure.
 ///
 /// To use a handle, first call [`enter`](Self::enter) to acquire a [`ReadGuard`]. This is similar
@@ -39,7 +43,7 @@ pub use factory::ReadHandleFactory;
 pub struct ReadHandle<T> {
     pub(crate) inner: Arc<AtomicPtr<T>>,
     pub(crate) epochs: crate::Epochs,
-    epoch: Arc<AtomicUsize>,
+    epoch: Arc<crate::Epoch>,
     epoch_i: usize,
     enters: Cell<usize>,
 
@@ -55,7 +59,7 @@ impl<T> Drop for ReadHandle<T> {
     fn drop(&mut self) {
         // epoch must already be even for us to have &mut self,
         // so okay to lock since we're not holding up the epoch anyway.
-        let e = self.epochs.lock().unwrap().remove(self.epoch_i);
+        let e = sync::lock(&self.epochs).remove(self.epoch_i);
         assert!(Arc::ptr_eq(&e, &self.epoch));
         assert_eq!(self.enters.get(), 0);
     }
@@ -72,7 +76,11 @@ impl<T> fmt::Debug for ReadHandle<T> {
 
 impl<T> Clone for ReadHandle<T> {
     fn clone(&self) -> Self {
-        ReadHandle::new_with_arc(Arc::clone(&self.inner), Arc::clone(&self.epochs))
+        ReadHandle::new_with_arc(
+            Arc::clone(&self.inner),
+            Arc::clone(&self.epochs),
+            self.epoch.label.clone(),
+        )
     }
 }
 
@@ -80,14 +88,18 @@ impl<T> ReadHandle<T> {
     pub(crate) fn new(inner: T, epochs: crate::Epochs) -> Self {
         let store = Box::into_raw(Box::new(inner));
         let inner = Arc::new(AtomicPtr::new(store));
-        Self::new_with_arc(inner, epochs)
+        Self::new_with_arc(inner, epochs, None)
     }
 
-    fn new_with_arc(inner: Arc<AtomicPtr<T>>, epochs: crate::Epochs) -> Self {
+    fn new_with_arc(
+        inner: Arc<AtomicPtr<T>>,
+        epochs: crate::Epochs,
+        label: Option<String>,
+    ) -> Self {
         // tell writer about our epoch tracker
-        let epoch = Arc::new(AtomicUsize::new(0));
+        let epoch = Arc::new(crate::Epoch::new(label));
         // okay to lock, since we're not holding up the epoch
-        let epoch_i = epochs.lock().unwrap().insert(Arc::clone(&epoch));
+        let epoch_i = sync::lock(&epochs).insert(Arc::clone(&epoch));
 
         Self {
             epochs,
@@ -107,6 +119,32 @@ impl<T> ReadHandle<T> {
             epochs: Arc::clone(&self.epochs),
         }
     }
+
+    /// Create a [`WeakReadHandle`] that can produce new [`ReadHandle`]s for as long as the
+    /// [`WriteHandle`] lives, without holding up the writer in the meantime.
+    ///
+    /// ```
+    /// # use left_right::{Absorb, ReadHandle};
+    /// # #[derive(Clone)]
+    /// # struct Data;
+    /// # impl Absorb<()> for Data {
+    /// #     fn absorb_first(&mut self, _: &mut (), _: &Self) {}
+    /// #     fn sync_with(&mut self, _: &Self) {}
+    /// # }
+    /// let (w, r) = left_right::new_from_empty::<Data, ()>(Data);
+    /// let weak = r.downgrade();
+    /// drop(r);
+    /// assert!(weak.upgrade().is_some());
+    /// drop(w);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> WeakReadHandle<T> {
+        WeakReadHandle {
+            inner: Arc::clone(&self.inner),
+            epochs: Arc::clone(&self.epochs),
+            _unimpl_send: PhantomData,
+        }
+    }
 }
 
 impl<T> ReadHandle<T> {
@@ -165,7 +203,7 @@ impl<T> ReadHandle<T> {
         // in all cases, using a pointer we read *after* updating our epoch is safe.
 
         // so, update our epoch tracker.
-        self.epoch.fetch_add(1, Ordering::AcqRel);
+        self.epoch.counter.fetch_add(1, Ordering::AcqRel);
 
         // ensure that the pointer read happens strictly after updating the epoch
         fence(Ordering::SeqCst);
@@ -180,6 +218,7 @@ impl<T> ReadHandle<T> {
             // add a guard to ensure we restore read parity even if we panic
             let enters = self.enters.get() + 1;
             self.enters.set(enters);
+            guard::hold(&self.epoch.counter);
             Some(ReadGuard {
                 handle: guard::ReadHandleState::from(self),
                 t: r_handle,
@@ -187,11 +226,78 @@ impl<T> ReadHandle<T> {
         } else {
             // the writehandle has been dropped, and so has both copies,
             // so restore parity and return None
-            self.epoch.fetch_add(1, Ordering::AcqRel);
+            self.epoch.counter.fetch_add(1, Ordering::AcqRel);
             None
         }
     }
 
+    /// Take out a guarded live reference to the read copy of the `T` that is not tied to the
+    /// lifetime of this handle.
+    ///
+    /// This works like [`enter`](Self::enter), except that the returned [`OwnedReadGuard`] keeps
+    /// its own clone of this handle alive instead of borrowing it. Note that this means that,
+    /// like cloning a handle, this method takes a lock.
+    ///
+    /// If the `WriteHandle` has been dropped, this function returns `None`.
+    ///
+    /// ```
+    /// use left_right::{OwnedReadGuard, ReadHandle};
+    ///
+    /// struct Snapshot {
+    ///     names: OwnedReadGuard<Vec<String>>,
+    /// }
+    ///
+    /// fn snapshot(handle: &ReadHandle<Vec<String>>) -> Option<Snapshot> {
+    ///     handle.enter_owned().map(|names| Snapshot { names })
+    /// }
+    /// ```
+    pub fn enter_owned(&self) -> Option<OwnedReadGuard<T>> {
+        let handle = self.clone();
+        let guard = handle.enter()?;
+        let t = NonNull::from(&*guard);
+        // the guard's epoch is released by the OwnedReadGuard instead.
+        std::mem::forget(guard);
+        Some(OwnedReadGuard { t, handle })
+    }
+
+    /// Take out a copy of the read copy of the `T`.
+    ///
+    /// The read copy is only held on to for as long as it takes to clone it. Unlike a guard, the
+    /// returned `T` does not hold up [`WriteHandle::publish`], and can be sent to other threads or
+    /// held across an `.await`.
+    ///
+    /// If the `WriteHandle` has been dropped, this function returns `None`.
+    pub fn snapshot(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.read_with(T::clone)
+    }
+
+    /// Compute a value from the read copy of the `T`.
+    ///
+    /// This works like [`snapshot`](Self::snapshot), except that it only copies out what `f`
+    /// returns, which is handy if `T` is large or not `Clone`.
+    ///
+    /// If the `WriteHandle` has been dropped, this function returns `None`.
+    ///
+    /// ```
+    /// use left_right::ReadHandle;
+    ///
+    /// async fn report(handle: &ReadHandle<Vec<String>>) {
+    ///     // the read copy is released before the .await, so publishes need not wait for it
+    ///     let names = handle.read_with(|names| names.join(", "));
+    ///     send(names.unwrap_or_default()).await;
+    /// }
+    /// # async fn send(_: String) {}
+    /// ```
+    pub fn read_with<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.enter().map(|guard| f(&guard))
+    }
+
     /// Returns true if the [`WriteHandle`] has been dropped.
     pub fn was_dropped(&self) -> bool {
         self.inner.load(Ordering::Acquire).is_null()
@@ -34,6 +34,65 @@ impl<T> ReadHandleFactory<T> {
     /// Produce a new [`ReadHandle`] to the same left-right data structure as this factory was
     /// originally produced from.
     pub fn handle(&self) -> ReadHandle<T> {
-        ReadHandle::new_with_arc(Arc::clone(&self.inner), Arc::clone(&self.epochs))
+        ReadHandle::new_with_arc(Arc::clone(&self.inner), Arc::clone(&self.epochs), None)
+    }
+
+    /// Produce a new [`ReadHandle`] like [`handle`](Self::handle), but with the given label.
+    ///
+    /// The label is reported to the callback set with
+    /// [`WriteHandle::set_wait_diagnostics`](crate::WriteHandle::set_wait_diagnostics) if the
+    /// handle holds up a publish, which helps identify which reader is to blame. Clones of the
+    /// returned handle share its label.
+    pub fn labeled(&self, label: impl Into<String>) -> ReadHandle<T> {
+        ReadHandle::new_with_arc(
+            Arc::clone(&self.inner),
+            Arc::clone(&self.epochs),
+            Some(label.into()),
+        )
+    }
+
+    /// Produce a new [`ReadHandle`] that only lives for the duration of `f`.
+    ///
+    /// Since `f` only gets to borrow the handle, the handle cannot escape the call, and is
+    /// guaranteed to be dropped (and its epoch retired) when `f` returns, even if it panics. This
+    /// is handy for short-lived tasks, like those run on a thread pool:
+    ///
+    /// ```
+    /// use left_right::ReadHandleFactory;
+    /// use std::thread;
+    ///
+    /// fn spawn_readers(factory: &ReadHandleFactory<Vec<i32>>) -> Vec<thread::JoinHandle<usize>> {
+    ///     (0..4)
+    ///         .map(|_| {
+    ///             let factory = factory.clone();
+    ///             thread::spawn(move || {
+    ///                 factory.scoped(|r| r.enter().map(|v| v.len()).unwrap_or(0))
+    ///             })
+    ///         })
+    ///         .collect()
+    /// }
+    /// # struct Push(i32);
+    /// # impl left_right::Absorb<Push> for Vec<i32> {
+    /// #     fn absorb_first(&mut self, operation: &mut Push, _: &Self) {
+    /// #         self.push(operation.0);
+    /// #     }
+    /// #     fn sync_with(&mut self, first: &Self) {
+    /// #         self.clone_from(first);
+    /// #     }
+    /// # }
+    /// # let (mut w, r) = left_right::new::<Vec<i32>, Push>();
+    /// # w.append(Push(1)).publish();
+    /// # let lens: Vec<_> = spawn_readers(&r.factory())
+    /// #     .into_iter()
+    /// #     .map(|t| t.join().unwrap())
+    /// #     .collect();
+    /// # assert_eq!(lens, vec![1; 4]);
+    /// ```
+    pub fn scoped<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&ReadHandle<T>) -> R,
+    {
+        let handle = self.handle();
+        f(&handle)
     }
 }
@@ -1,6 +1,52 @@
 use crate::sync::{AtomicUsize, Ordering};
 use std::cell::Cell;
+use std::fmt;
 use std::mem;
+use std::ptr::NonNull;
+
+#[cfg(all(debug_assertions, not(loom)))]
+std::thread_local! {
+    // the epochs held by the guards of this thread, so that a publish on the same thread can tell
+    // that it is about to wait for itself.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static HELD: std::cell::RefCell<Vec<*const AtomicUsize>> = std::cell::RefCell::new(Vec::new());
+}
+
+/// Note that the current thread now holds `epoch`.
+pub(super) fn hold(epoch: &AtomicUsize) {
+    #[cfg(all(debug_assertions, not(loom)))]
+    let _ = HELD.try_with(|held| held.borrow_mut().push(epoch));
+    #[cfg(not(all(debug_assertions, not(loom))))]
+    let _ = epoch;
+}
+
+/// Note that the current thread no longer holds `epoch`.
+pub(super) fn release(epoch: &AtomicUsize) {
+    #[cfg(all(debug_assertions, not(loom)))]
+    let _ = HELD.try_with(|held| {
+        let mut held = held.borrow_mut();
+        if let Some(i) = held.iter().position(|&e| std::ptr::eq(e, epoch)) {
+            held.swap_remove(i);
+        }
+    });
+    #[cfg(not(all(debug_assertions, not(loom))))]
+    let _ = epoch;
+}
+
+/// Returns true if a [`ReadGuard`] on the current thread holds `epoch`.
+///
+/// This is only tracked in debug builds, and is always false otherwise.
+pub(crate) fn is_held(epoch: &AtomicUsize) -> bool {
+    #[cfg(all(debug_assertions, not(loom)))]
+    return HELD
+        .try_with(|held| held.borrow().iter().any(|&e| std::ptr::eq(e, epoch)))
+        .unwrap_or(false);
+    #[cfg(not(all(debug_assertions, not(loom))))]
+    return {
+        let _ = epoch;
+        false
+    };
+}
 
 #[derive(Debug, Copy, Clone)]
 pub(super) struct ReadHandleState<'rh> {
@@ -11,7 +57,7 @@ pub(super) struct ReadHandleState<'rh> {
 impl<'rh, T> From<&'rh super::ReadHandle<T>> for ReadHandleState<'rh> {
     fn from(rh: &'rh super::ReadHandle<T>) -> Self {
         Self {
-            epoch: &rh.epoch,
+            epoch: &rh.epoch.counter,
             enters: &rh.enters,
         }
     }
@@ -24,7 +70,15 @@ impl<'rh, T> From<&'rh super::ReadHandle<T>> for ReadHandleState<'rh> {
 /// dropped.
 ///
 /// To scope the guard to a subset of the data in `T`, use [`map`](Self::map) and
-/// [`try_map`](Self::try_map).
+/// [`try_map`](Self::try_map). To split it into guards over disjoint parts of `T`, use
+/// [`map_split`](Self::map_split).
+///
+/// A `ReadGuard` cannot be sent to another thread. In particular, this means that a future that
+/// holds one across an `.await` is not `Send` either, which keeps such a future from stalling
+/// writers for as long as it is suspended on a multi-threaded executor. If you need data that can
+/// move between threads, copy it out with
+/// [`ReadHandle::snapshot`](super::ReadHandle::snapshot) or
+/// [`ReadHandle::read_with`](super::ReadHandle::read_with) instead.
 #[derive(Debug)]
 pub struct ReadGuard<'rh, T: ?Sized> {
     // NOTE: _technically_ this is more like &'self.
@@ -99,6 +153,58 @@ impl<'rh, T: ?Sized> ReadGuard<'rh, T> {
         mem::forget(orig);
         Some(rg)
     }
+
+    /// Splits a `ReadGuard` into multiple `ReadGuard`s for different components of the borrowed
+    /// data.
+    ///
+    /// The underlying data remains pinned (that is, [`WriteHandle::publish`] stays blocked) until
+    /// _both_ returned guards have been dropped.
+    ///
+    /// This is an associated function that needs to be used as `ReadGuard::map_split(...)`, since
+    /// a method would interfere with methods of the same name on the contents of a `Readguard`
+    /// used through `Deref`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use left_right::{ReadGuard, ReadHandle};
+    ///
+    /// fn get_parts(
+    ///     handle: &ReadHandle<Vec<(String, i32)>>,
+    ///     i: usize,
+    /// ) -> Option<(ReadGuard<'_, str>, ReadGuard<'_, i32>)> {
+    ///     handle.enter().map(|guard| {
+    ///         ReadGuard::map_split(guard, |t| {
+    ///             (&*t[i].0, &t[i].1)
+    ///         })
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// [`WriteHandle::publish`]: crate::WriteHandle::publish
+    pub fn map_split<F, U: ?Sized, V: ?Sized>(
+        orig: Self,
+        f: F,
+    ) -> (ReadGuard<'rh, U>, ReadGuard<'rh, V>)
+    where
+        F: for<'a> FnOnce(&'a T) -> (&'a U, &'a V),
+    {
+        let (u, v) = f(orig.t);
+        // the second guard also needs to be accounted for before we release our epoch.
+        orig.handle.enters.set(orig.handle.enters.get() + 1);
+        let rgs = (
+            ReadGuard {
+                t: u,
+                handle: orig.handle,
+            },
+            ReadGuard {
+                t: v,
+                handle: orig.handle,
+            },
+        );
+        mem::forget(orig);
+        rgs
+    }
 }
 
 impl<'rh, T: ?Sized> AsRef<T> for ReadGuard<'rh, T> {
@@ -120,7 +226,86 @@ impl<'rh, T: ?Sized> Drop for ReadGuard<'rh, T> {
         self.handle.enters.set(enters);
         if enters == 0 {
             // We are the last guard to be dropped -- now release our epoch.
+            release(self.handle.epoch);
             self.handle.epoch.fetch_add(1, Ordering::AcqRel);
         }
     }
 }
+
+/// A guard wrapping a live reference into a left-right protected `T` that owns its read handle.
+///
+/// This behaves just like a [`ReadGuard`], except that it keeps its own
+/// [`ReadHandle`](super::ReadHandle) alive internally rather than borrowing one. It is therefore
+/// not tied to the lifetime of a handle, and can be stored in a struct or returned from a function.
+/// Create one with [`ReadHandle::enter_owned`](super::ReadHandle::enter_owned).
+///
+/// As long as this guard lives, the `T` being read cannot change, and any call to
+/// [`WriteHandle::publish`](crate::WriteHandle::publish) will block. Like a `ReadGuard`, an
+/// `OwnedReadGuard` cannot be sent to another thread.
+pub struct OwnedReadGuard<T> {
+    // NOTE: valid until the epoch of `handle` is released, which only happens on drop.
+    pub(super) t: NonNull<T>,
+    pub(super) handle: super::ReadHandle<T>,
+}
+
+impl<T> fmt::Debug for OwnedReadGuard<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedReadGuard")
+            .field("t", &**self)
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<T> AsRef<T> for OwnedReadGuard<T> {
+    fn as_ref(&self) -> &T {
+        // safety: our handle's epoch has been bumped since we read the pointer, and is only
+        // bumped again when we are dropped.
+        unsafe { self.t.as_ref() }
+    }
+}
+
+impl<T> std::ops::Deref for OwnedReadGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<T> Drop for OwnedReadGuard<T> {
+    fn drop(&mut self) {
+        // release the epoch just like the `ReadGuard` we were made from would have. this happens
+        // before `handle` itself is dropped, which requires that it has no guards left.
+        drop(ReadGuard {
+            t: self.as_ref(),
+            handle: ReadHandleState::from(&self.handle),
+        });
+    }
+}
+
+/// `ReadGuard` cannot be sent across threads:
+///
+/// ```compile_fail
+/// use left_right::ReadGuard;
+///
+/// fn is_send<T: Send>() {
+///   // dummy function just used for its parameterized type bound
+/// }
+///
+/// is_send::<ReadGuard<'static, u64>>()
+/// ```
+///
+/// Neither can `OwnedReadGuard`, even though it does not borrow its `ReadHandle`:
+///
+/// ```compile_fail
+/// use left_right::OwnedReadGuard;
+///
+/// fn is_send<T: Send>() {}
+///
+/// is_send::<OwnedReadGuard<u64>>()
+/// ```
+#[allow(dead_code)]
+struct CheckReadGuardSend;
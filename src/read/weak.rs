@@ -0,0 +1,59 @@
+use super::ReadHandle;
+use crate::sync::{Arc, AtomicPtr};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A handle that can produce a [`ReadHandle`] for as long as the left-right data structure
+/// exists, without itself being a reader.
+///
+/// Unlike a [`ReadHandle`], a `WeakReadHandle` does not occupy a slot among the readers that
+/// [`WriteHandle::publish`](crate::WriteHandle::publish) has to check on, which makes it suitable
+/// for long-lived registries that only occasionally read the data. It also does not keep the data
+/// alive: once the [`WriteHandle`](crate::WriteHandle) is dropped,
+/// [`upgrade`](WeakReadHandle::upgrade) returns `None`.
+///
+/// Upgrading internally takes the same lock as cloning a [`ReadHandle`], so you should not expect
+/// upgrading rapidly to scale well.
+pub struct WeakReadHandle<T> {
+    pub(super) inner: Arc<AtomicPtr<T>>,
+    pub(super) epochs: crate::Epochs,
+
+    // see the equivalent field on `ReadHandle`.
+    pub(super) _unimpl_send: PhantomData<*const T>,
+}
+unsafe impl<T> Send for WeakReadHandle<T> where T: Sync {}
+unsafe impl<T> Sync for WeakReadHandle<T> where T: Sync {}
+
+impl<T> fmt::Debug for WeakReadHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakReadHandle")
+            .field("epochs", &self.epochs)
+            .finish()
+    }
+}
+
+impl<T> Clone for WeakReadHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            epochs: Arc::clone(&self.epochs),
+            _unimpl_send: PhantomData,
+        }
+    }
+}
+
+impl<T> WeakReadHandle<T> {
+    /// Produce a new [`ReadHandle`] to the same left-right data structure as this handle was
+    /// originally produced from.
+    ///
+    /// Returns `None` if the [`WriteHandle`](crate::WriteHandle) has been dropped.
+    pub fn upgrade(&self) -> Option<ReadHandle<T>> {
+        let handle =
+            ReadHandle::new_with_arc(Arc::clone(&self.inner), Arc::clone(&self.epochs), None);
+        if handle.was_dropped() {
+            None
+        } else {
+            Some(handle)
+        }
+    }
+}
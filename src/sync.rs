@@ -20,3 +20,34 @@ pub(crate) fn fence(ord: Ordering) {
 pub(crate) use std::sync::atomic::{fence, AtomicPtr, AtomicUsize, Ordering};
 #[cfg(not(loom))]
 pub(crate) use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Lock the given mutex, even if it has been poisoned.
+///
+/// The epochs are never left in an inconsistent state when a thread panics while holding their
+/// lock, as that only happens if user code (like [`Absorb`](crate::Absorb)) panics during a
+/// publish. Ignoring the poisoning keeps read handles usable after such a panic.
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Pads and aligns a value to the size of a cache line, so that it never shares a cache line with
+/// another value.
+///
+/// We use 128 bytes since modern x86_64 CPUs prefetch cache lines in pairs, and some ARM CPUs have
+/// 128-byte cache lines to begin with.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+pub(crate) struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    pub(crate) fn new(t: T) -> Self {
+        Self(t)
+    }
+}
+
+impl<T> std::ops::Deref for CachePadded<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -1,13 +1,15 @@
 use crate::read::ReadHandle;
 use crate::Absorb;
 
-use crate::sync::{fence, Arc, AtomicUsize, MutexGuard, Ordering};
+use crate::sync::{self, fence, Arc, MutexGuard, Ordering};
+use std::cmp;
 use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::ops::DerefMut;
 use std::ptr::NonNull;
 #[cfg(test)]
 use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant};
 use std::{fmt, thread};
 
 /// A writer handle to a left-right guarded data structure.
@@ -30,8 +32,18 @@ where
     w_handle: NonNull<T>,
     oplog: VecDeque<O>,
     swap_index: usize,
+    /// How many operations past `swap_index` an unfinished incremental publish covers.
+    cut: Option<usize>,
+    /// How many operations an unfinished incremental publish has absorbed into w_handle.
+    absorbed: usize,
     r_handle: ReadHandle<T>,
     last_epochs: Vec<usize>,
+    wait_strategy: WaitStrategy,
+    wait_diagnostics: Option<WaitDiagnostics>,
+    auto_publish: AutoPublish,
+    /// Only tracked for `AutoPublish::EveryDuration`, so that targets without a clock work.
+    last_publish: Option<Instant>,
+    max_pending_ops: Option<usize>,
     #[cfg(test)]
     refreshes: usize,
     #[cfg(test)]
@@ -42,6 +54,8 @@ where
     second: bool,
     /// If we call `Self::take` the drop needs to be different.
     taken: bool,
+    /// A publish panicked while absorbing operations into w_handle.
+    poisoned: bool,
 }
 
 // safety: if a `WriteHandle` is sent across a thread boundary, we need to be able to take
@@ -68,12 +82,150 @@ where
             .field("oplog", &self.oplog)
             .field("swap_index", &self.swap_index)
             .field("r_handle", &self.r_handle)
+            .field("wait_strategy", &self.wait_strategy)
+            .field("auto_publish", &self.auto_publish)
             .field("first", &self.first)
             .field("second", &self.second)
+            .field("poisoned", &self.poisoned)
             .finish()
     }
 }
 
+/// How a [`WriteHandle`] waits for readers to depart the write copy when publishing.
+///
+/// Each strategy first checks on the readers that may still be in the write copy, and only
+/// pauses if some of them remain. Set the strategy for a handle using
+/// [`WriteHandle::set_wait_strategy`].
+#[derive(Debug, Clone, Copy)]
+pub enum WaitStrategy {
+    /// Busy-loop until all readers have departed.
+    Spin,
+    /// Busy-loop for a short while, then yield the writer's time slice between checks.
+    ///
+    /// This is the default.
+    Yield,
+    /// Busy-loop for a short while, then park the writer between checks, for exponentially
+    /// increasing periods of time up to `timeout`.
+    Park {
+        /// The longest the writer will park for between two checks.
+        timeout: Duration,
+    },
+    /// Call the given function between checks.
+    ///
+    /// The function is given the number of times the writer has had to pause so far during the
+    /// current publish.
+    Custom(fn(usize)),
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for WaitStrategy {
+    fn default() -> Self {
+        WaitStrategy::Yield
+    }
+}
+
+impl WaitStrategy {
+    fn pause(&self, iter: usize) {
+        // how eagerly should we retry?
+        match *self {
+            WaitStrategy::Spin => {}
+            WaitStrategy::Yield => {
+                if iter >= 20 {
+                    thread::yield_now();
+                }
+            }
+            WaitStrategy::Park { timeout } => {
+                if iter >= 20 {
+                    let backoff = Duration::from_micros(1 << cmp::min(iter - 20, 20));
+                    thread::park_timeout(cmp::min(backoff, timeout));
+                }
+            }
+            WaitStrategy::Custom(f) => f(iter),
+        }
+    }
+}
+
+/// The error returned by [`WriteHandle::try_publish`] when the handle is
+/// [poisoned](WriteHandle::is_poisoned).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Poisoned;
+
+impl fmt::Display for Poisoned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a previous publish panicked while absorbing operations")
+    }
+}
+
+impl std::error::Error for Poisoned {}
+
+/// A reader that is holding up a publish.
+///
+/// These are reported to the callback set with [`WriteHandle::set_wait_diagnostics`].
+#[derive(Debug, Clone)]
+pub struct BlockingReader {
+    slot: usize,
+    label: Option<String>,
+    publish_waited: Duration,
+}
+
+impl BlockingReader {
+    /// An identifier for the reader that is unique among the readers that currently exist.
+    ///
+    /// Identifiers are re-used once their [`ReadHandle`] is dropped.
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// The label given to the reader's handle, if any.
+    ///
+    /// See [`ReadHandleFactory::labeled`](crate::ReadHandleFactory::labeled).
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// How long the publish had been waiting for readers when this reader was reported.
+    ///
+    /// This is measured from when the publish first had to wait, so it is the same for every
+    /// reader in a report. It is _not_ the age of this reader's epoch: there is no record of when
+    /// readers enter, and the reader may well have been reading for longer than this.
+    pub fn publish_waited(&self) -> Duration {
+        self.publish_waited
+    }
+}
+
+struct WaitDiagnostics {
+    threshold: Duration,
+    report: Box<dyn FnMut(&[BlockingReader]) + Send>,
+}
+
+/// When a [`WriteHandle`] should publish on its own as operations are appended.
+///
+/// Set the policy for a handle using [`WriteHandle::set_auto_publish`].
+#[derive(Debug, Clone, Copy)]
+pub enum AutoPublish {
+    /// Publish once at least this many operations are pending.
+    EveryNOps(usize),
+    /// Publish if at least this much time has passed since the last publish.
+    ///
+    /// Note that this is only checked when operations are appended. If no operations are appended
+    /// after the deadline has passed, no publish happens.
+    ///
+    /// This is the only policy that reads the system clock, which is not available on some
+    /// targets, such as `wasm32-unknown-unknown`.
+    EveryDuration(Duration),
+    /// Only publish when explicitly asked to.
+    ///
+    /// This is the default.
+    Never,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for AutoPublish {
+    fn default() -> Self {
+        AutoPublish::Never
+    }
+}
+
 /// A **smart pointer** to an owned backing data structure. This makes sure that the
 /// data is dropped correctly (using [`Absorb::drop_second`]).
 ///
@@ -115,6 +267,10 @@ impl<T: Absorb<O>, O> DerefMut for Taken<T, O> {
 }
 
 impl<T: Absorb<O>, O> Taken<T, O> {
+    /// Take the inner backing data structure out of this smart pointer.
+    ///
+    /// # Safety
+    ///
     /// This is unsafe because you must call [`Absorb::drop_second`] in
     /// case just dropping `T` is not safe and sufficient.
     ///
@@ -154,6 +310,16 @@ where
         // Disallow taking again.
         self.taken = true;
 
+        if self.poisoned {
+            // we can neither bring the copies up to date nor know that it is safe to drop the
+            // write copy, so leak both. readers may still be in the read copy, but since it is
+            // never freed, that's fine. we just have to make sure no new readers enter it.
+            self.r_handle
+                .inner
+                .store(ptr::null_mut(), Ordering::Release);
+            return None;
+        }
+
         // first, ensure both copies are up to date
         // (otherwise safely dropping the possibly duplicated w_handle data is a pain)
         if self.first || !self.oplog.is_empty() {
@@ -169,7 +335,7 @@ where
 
         // now, wait for all readers to depart
         let epochs = Arc::clone(&self.epochs);
-        let mut epochs = epochs.lock().unwrap();
+        let mut epochs = sync::lock(&epochs);
         self.wait(&mut epochs);
 
         // ensure that the subsequent epoch reads aren't re-ordered to before the swap
@@ -219,8 +385,15 @@ where
             w_handle: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(w_handle))) },
             oplog: VecDeque::new(),
             swap_index: 0,
+            cut: None,
+            absorbed: 0,
             r_handle,
             last_epochs: Vec::new(),
+            wait_strategy: WaitStrategy::default(),
+            wait_diagnostics: None,
+            auto_publish: AutoPublish::default(),
+            last_publish: None,
+            max_pending_ops: None,
             #[cfg(test)]
             is_waiting: Arc::new(AtomicBool::new(false)),
             #[cfg(test)]
@@ -228,12 +401,16 @@ where
             first: true,
             second: true,
             taken: false,
+            poisoned: false,
         }
     }
 
-    fn wait(&mut self, epochs: &mut MutexGuard<'_, slab::Slab<Arc<AtomicUsize>>>) {
+    #[allow(clippy::manual_is_multiple_of)]
+    fn wait(&mut self, epochs: &mut MutexGuard<'_, slab::Slab<Arc<crate::Epoch>>>) {
         let mut iter = 0;
         let mut starti = 0;
+        let mut started = None;
+        let mut reported = false;
 
         #[cfg(test)]
         {
@@ -260,7 +437,7 @@ where
                     continue;
                 }
 
-                let now = epoch.load(Ordering::Acquire);
+                let now = epoch.counter.load(Ordering::Acquire);
                 if now != self.last_epochs[ri] {
                     // reader must have seen the last swap, since they have done at least one
                     // operation since we last looked at their epoch, which _must_ mean that they
@@ -270,13 +447,36 @@ where
                     // continue from this reader's epoch
                     starti = ii;
 
+                    assert!(
+                        !crate::read::is_held(&epoch.counter),
+                        "publish would wait forever for a ReadGuard held by the calling thread"
+                    );
+
                     if !cfg!(loom) {
-                        // how eagerly should we retry?
-                        if iter != 20 {
-                            iter += 1;
-                        } else {
-                            thread::yield_now();
+                        if let Some(ref mut diagnostics) = self.wait_diagnostics {
+                            let waited = started.get_or_insert_with(Instant::now).elapsed();
+                            if !reported && waited >= diagnostics.threshold {
+                                let last_epochs = &self.last_epochs;
+                                let blocking: Vec<_> = epochs
+                                    .iter()
+                                    .filter(|&(ri, epoch)| {
+                                        last_epochs[ri] % 2 != 0
+                                            && epoch.counter.load(Ordering::Acquire)
+                                                == last_epochs[ri]
+                                    })
+                                    .map(|(ri, epoch)| BlockingReader {
+                                        slot: ri,
+                                        label: epoch.label.clone(),
+                                        publish_waited: waited,
+                                    })
+                                    .collect();
+                                (diagnostics.report)(&blocking);
+                                reported = true;
+                            }
                         }
+
+                        self.wait_strategy.pause(iter);
+                        iter += 1;
                     }
 
                     #[cfg(loom)]
@@ -300,6 +500,55 @@ where
     /// take some time, especially if readers are executing slow operations, or if there are many
     /// of them.
     pub fn publish(&mut self) -> &mut Self {
+        let swapped = self.publish_inner(None);
+        debug_assert!(swapped);
+        self
+    }
+
+    /// Publish all operations appended to the log to readers, unless the handle is poisoned.
+    ///
+    /// This works like [`publish`](Self::publish), except that it returns an error rather than
+    /// panicking if a previous publish panicked. Call [`recover`](Self::recover) to be able to
+    /// publish again.
+    pub fn try_publish(&mut self) -> Result<&mut Self, Poisoned> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
+        Ok(self.publish())
+    }
+
+    /// Publish operations appended to the log to readers, applying at most `max_ops` of them per
+    /// call.
+    ///
+    /// [`publish`](Self::publish) must replay the entire operational log onto the write copy
+    /// before it can swap the copies, which can stall the writer for a long time if many operations
+    /// have accumulated (after a bulk load, for example). This method instead applies at most
+    /// `max_ops` operations to the write copy and then returns. Once every operation that was
+    /// pending when the first of a series of calls was made has been applied, the copies are
+    /// swapped and `true` is returned. Operations appended in the meantime are left for the next
+    /// publish, so repeated calls are guaranteed to eventually swap.
+    ///
+    /// Each call still has to wait for readers to depart the write copy, though after the first
+    /// call in a series that wait is usually immediate. Calling `publish` while an incremental
+    /// publish is ongoing completes it, and exposes all pending operations as usual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_ops` is 0, since no progress could then be made.
+    pub fn publish_incremental(&mut self, max_ops: usize) -> bool {
+        assert!(
+            max_ops > 0,
+            "an incremental publish must apply at least one operation"
+        );
+        self.publish_inner(Some(max_ops))
+    }
+
+    fn publish_inner(&mut self, max_ops: Option<usize>) -> bool {
+        assert!(
+            !self.poisoned,
+            "a previous publish panicked; call WriteHandle::recover before publishing again"
+        );
+
         // we need to wait until all epochs have changed since the swaps *or* until a "finished"
         // flag has been observed to be on for two subsequent iterations (there still may be some
         // readers present since we did the previous refresh)
@@ -308,7 +557,7 @@ where
         // only block on pre-existing readers, and they are never waiting to push onto epochs
         // unless they have finished reading.
         let epochs = Arc::clone(&self.epochs);
-        let mut epochs = epochs.lock().unwrap();
+        let mut epochs = sync::lock(&epochs);
 
         self.wait(&mut epochs);
 
@@ -326,28 +575,69 @@ where
                     .unwrap()
             };
 
+            // if any of the absorbs below panic, w_handle is left in an unknown state.
+            self.poisoned = true;
+
             if self.second {
                 Absorb::sync_with(w_handle, r_handle);
                 self.second = false
             }
 
+            let mut budget = max_ops.unwrap_or(usize::MAX);
+
+            // an incremental publish only covers the operations that were there when it started,
+            // otherwise a steady stream of appends could keep it from ever swapping.
+            let cut = match max_ops {
+                Some(_) => *self.cut.get_or_insert(self.oplog.len() - self.swap_index),
+                None => self.oplog.len() - self.swap_index,
+            };
+
             // the w_handle copy has not seen any of the writes in the oplog
             // the r_handle copy has not seen any of the writes following swap_index
-            if self.swap_index != 0 {
+            let drain = cmp::min(self.swap_index, budget);
+            if drain != 0 {
                 // we can drain out the operations that only the w_handle copy needs
                 //
                 // NOTE: the if above is because drain(0..0) would remove 0
-                for op in self.oplog.drain(0..self.swap_index) {
+                //
+                // the drained operations leave the oplog even if absorbing them panics, so account
+                // for that up front to keep swap_index within the oplog.
+                self.swap_index -= drain;
+                budget -= drain;
+                let mut ops = self.oplog.drain(0..drain);
+                T::absorb_second_batch(w_handle, &mut ops, r_handle);
+                // any operations left in ops would otherwise be dropped without reaching w_handle
+                for op in ops {
                     T::absorb_second(w_handle, op, r_handle);
                 }
             }
-            // we cannot give owned operations to absorb_first
-            // since they'll also be needed by the r_handle copy
-            for op in self.oplog.iter_mut() {
-                T::absorb_first(w_handle, op, r_handle);
+            if self.swap_index == 0 {
+                // we cannot give owned operations to absorb_first
+                // since they'll also be needed by the r_handle copy
+                let n = cmp::min(cut - self.absorbed, budget);
+                if n != 0 {
+                    let mut ops = self.oplog.iter_mut().skip(self.absorbed).take(n);
+                    T::absorb_first_batch(w_handle, &mut ops, r_handle);
+                    // any operations left in ops would otherwise be skipped by w_handle
+                    for op in ops {
+                        T::absorb_first(w_handle, op, r_handle);
+                    }
+                    self.absorbed += n;
+                }
+            }
+
+            self.poisoned = false;
+
+            if self.swap_index != 0 || self.absorbed != cut {
+                // out of budget. readers only ever enter the copy behind the atomic pointer, so
+                // none of them can reach w_handle before we come back to finish the job.
+                return false;
             }
+
             // the w_handle copy is about to become the r_handle, and can ignore the oplog
-            self.swap_index = self.oplog.len();
+            self.swap_index = cut;
+            self.absorbed = 0;
+            self.cut = None;
 
         // w_handle (the old r_handle) is now fully up to date!
         } else {
@@ -376,7 +666,11 @@ where
         fence(Ordering::SeqCst);
 
         for (ri, epoch) in epochs.iter() {
-            self.last_epochs[ri] = epoch.load(Ordering::Acquire);
+            self.last_epochs[ri] = epoch.counter.load(Ordering::Acquire);
+        }
+
+        if self.last_publish.is_some() {
+            self.last_publish = Some(Instant::now());
         }
 
         #[cfg(test)]
@@ -384,6 +678,70 @@ where
             self.refreshes += 1;
         }
 
+        true
+    }
+
+    /// Set how this handle waits for readers to depart the write copy when publishing.
+    ///
+    /// Defaults to [`WaitStrategy::Yield`].
+    pub fn set_wait_strategy(&mut self, strategy: WaitStrategy) -> &mut Self {
+        self.wait_strategy = strategy;
+        self
+    }
+
+    /// Report the readers that hold up a publish for longer than `threshold`.
+    ///
+    /// Once a publish has waited for readers to depart the write copy for at least `threshold`,
+    /// `report` is called with the readers it is still waiting for. This happens at most once per
+    /// publish. To tell readers apart, create their handles using
+    /// [`ReadHandleFactory::labeled`](crate::ReadHandleFactory::labeled).
+    ///
+    /// Note that `report` is called while holding the lock that guards the set of readers, so it
+    /// must not create or drop any [`ReadHandle`]s to this data structure.
+    pub fn set_wait_diagnostics<F>(&mut self, threshold: Duration, report: F) -> &mut Self
+    where
+        F: FnMut(&[BlockingReader]) + Send + 'static,
+    {
+        self.wait_diagnostics = Some(WaitDiagnostics {
+            threshold,
+            report: Box::new(report),
+        });
+        self
+    }
+
+    /// Set when this handle should publish on its own as operations are appended.
+    ///
+    /// The policy is only consulted when operations are appended, and only operations appended
+    /// after the first call to [`publish`](Self::publish) count towards it. Defaults to
+    /// [`AutoPublish::Never`].
+    pub fn set_auto_publish(&mut self, policy: AutoPublish) -> &mut Self {
+        if let AutoPublish::EveryDuration(_) = policy {
+            self.last_publish.get_or_insert_with(Instant::now);
+        } else {
+            self.last_publish = None;
+        }
+        self.auto_publish = policy;
+        self
+    }
+
+    /// Bound the number of operations that can be pending at any one time.
+    ///
+    /// Once `max` operations are pending, appending another one first publishes, which blocks
+    /// until all readers have left the write copy. Use [`try_append`](Self::try_append) to get the
+    /// operation back instead. Operations appended before the first call to
+    /// [`publish`](Self::publish) are applied immediately, and are never pending. Defaults to
+    /// `None`, which leaves the operational log unbounded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is `Some(0)`.
+    pub fn set_max_pending_ops(&mut self, max: Option<usize>) -> &mut Self {
+        assert_ne!(
+            max,
+            Some(0),
+            "at least one operation must be allowed to be pending"
+        );
+        self.max_pending_ops = max;
         self
     }
 
@@ -405,6 +763,78 @@ where
         self.swap_index < self.oplog.len()
     }
 
+    /// Returns true if a previous publish panicked while applying operations to the write copy.
+    ///
+    /// If an [`Absorb`] method panics during a publish, the write copy is left partially updated,
+    /// and can no longer be trusted to mirror the read copy. The handle is then _poisoned_:
+    /// readers keep seeing the data from the last successful publish, but any further attempt to
+    /// publish will panic until [`recover`](Self::recover) is called. Use
+    /// [`try_publish`](Self::try_publish) to get an error instead. Appending to a poisoned handle
+    /// never publishes on its own, regardless of [`AutoPublish`] and
+    /// [`set_max_pending_ops`](Self::set_max_pending_ops). A poisoned handle that is dropped leaks
+    /// both copies of the data rather than risk dropping an inconsistent one.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    /// Recover from a panic during a previous publish.
+    ///
+    /// This discards the write copy along with every operation that has not yet been published,
+    /// and replaces the write copy with `empty`, which is synchronized with the read copy (using
+    /// [`Absorb::sync_with`]) on the next publish. `empty` must be empty in the same sense as the
+    /// data structure given to [`new_from_empty`](crate::new_from_empty).
+    ///
+    /// The discarded write copy is dropped using [`Absorb::drop_first`], and the discarded
+    /// operations are dropped without being absorbed. Depending on the `Absorb` implementation,
+    /// this may leak some values.
+    ///
+    /// Does nothing if the handle is not [poisoned](Self::is_poisoned).
+    pub fn recover(&mut self, empty: T) -> &mut Self {
+        if !self.poisoned {
+            return self;
+        }
+
+        // the publish that panicked already waited for readers to depart w_handle, and they
+        // cannot have entered it since, but there's no harm in making sure.
+        let epochs = Arc::clone(&self.epochs);
+        let mut epochs = sync::lock(&epochs);
+        self.wait(&mut epochs);
+
+        // safety: w_handle was initially crated from a `Box`, and is not aliased.
+        Absorb::drop_first(unsafe { Box::from_raw(self.w_handle.as_ptr()) });
+        // safety: Box<T> is not null and covariant.
+        self.w_handle = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(empty))) };
+        self.second = true;
+
+        self.oplog.clear();
+        self.swap_index = 0;
+        self.absorbed = 0;
+        self.cut = None;
+        self.poisoned = false;
+        self
+    }
+
+    /// Returns the number of readers that may not yet have observed the last call to
+    /// [`publish`](Self::publish).
+    ///
+    /// These are the readers the next `publish` will have to wait for, so a growing lag is a sign
+    /// that readers are struggling to keep up. Note that this is only an estimate: readers may
+    /// depart at any time, including right after this method has looked at them.
+    ///
+    /// This method briefly takes the same lock as creating or dropping a [`ReadHandle`].
+    pub fn reader_lag(&self) -> usize {
+        let epochs = sync::lock(&self.epochs);
+        epochs
+            .iter()
+            .filter(|&(ri, epoch)| match self.last_epochs.get(ri) {
+                // see the comments in `wait` for why this is the condition for a lagging reader.
+                // readers with no entry in last_epochs registered after the last swap.
+                Some(&last) => last % 2 != 0 && epoch.counter.load(Ordering::Acquire) == last,
+                None => false,
+            })
+            .count()
+    }
+
     /// Append the given operation to the operational log.
     ///
     /// Its effects will not be exposed to readers until you call [`publish`](Self::publish).
@@ -413,6 +843,55 @@ where
         self
     }
 
+    /// Append the given operation to the operational log, unless it is full.
+    ///
+    /// This works like [`append`](Self::append), except that if the limit set with
+    /// [`set_max_pending_ops`](Self::set_max_pending_ops) has been reached, the operation is
+    /// returned rather than publishing to make room for it.
+    pub fn try_append(&mut self, op: O) -> Result<&mut Self, O> {
+        if self.is_full() {
+            return Err(op);
+        }
+        Ok(self.append(op))
+    }
+
+    /// Start a batch of operations that are all exposed to readers by the same publish.
+    ///
+    /// Consecutive calls to [`append`](Self::append) may end up in different publishes if an
+    /// [`AutoPublish`] policy or [`set_max_pending_ops`](Self::set_max_pending_ops) kicks in
+    /// between them. The operations of a [`Batch`] are instead held back until
+    /// [`Batch::publish`], which appends them all at once, ignoring both, and then publishes.
+    ///
+    /// ```
+    /// # #[derive(Clone)]
+    /// # struct Counter(i32);
+    /// # impl left_right::Absorb<i32> for Counter {
+    /// #     fn absorb_first(&mut self, operation: &mut i32, _: &Self) {
+    /// #         self.0 += *operation;
+    /// #     }
+    /// #     fn sync_with(&mut self, first: &Self) {
+    /// #         self.0 = first.0;
+    /// #     }
+    /// # }
+    /// # let (mut w, r) = left_right::new_from_empty::<Counter, i32>(Counter(0));
+    /// w.set_auto_publish(left_right::AutoPublish::EveryNOps(1));
+    /// w.batch().append(1).append(2).publish();
+    /// assert_eq!(r.enter().map(|c| c.0), Some(3));
+    /// ```
+    pub fn batch(&mut self) -> Batch<'_, T, O> {
+        Batch {
+            handle: self,
+            ops: Vec::new(),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        match self.max_pending_ops {
+            Some(max) => !self.first && self.oplog.len() - self.swap_index >= max,
+            None => false,
+        }
+    }
+
     /// Returns a raw pointer to the write copy of the data (the one readers are _not_ accessing).
     ///
     /// Note that it is only safe to mutate through this pointer if you _know_ that there are no
@@ -431,7 +910,15 @@ where
     /// Makes sure that all the pending operations are applied and waits till all the read handles
     /// have departed. Then it uses [`Absorb::drop_first`] to drop one of the copies of the data and
     /// returns the other copy as a [`Taken`] smart pointer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle is [poisoned](Self::is_poisoned).
     pub fn take(mut self) -> Taken<T, O> {
+        assert!(
+            !self.poisoned,
+            "a previous publish panicked; call WriteHandle::recover before taking the data"
+        );
         // It is always safe to `expect` here because `take_inner` is private
         // and it is only called here and in the drop impl. Since we have an owned
         // `self` we know the drop has not yet been called. And every first call of
@@ -472,12 +959,112 @@ where
             let r_handle = self.enter().expect("map has not yet been destroyed");
             // Because we are operating directly on the map, and nothing is aliased, we do want
             // to perform drops, so we invoke absorb_second.
+            let mut ops = ops.into_iter();
+            Absorb::absorb_second_batch(w_inner, &mut ops, &*r_handle);
             for op in ops {
                 Absorb::absorb_second(w_inner, op, &*r_handle);
             }
         } else {
-            self.oplog.extend(ops);
+            if self.max_pending_ops.is_some() {
+                for op in ops {
+                    // make room by exposing what is already pending to readers
+                    if self.is_full() && !self.poisoned {
+                        self.publish();
+                    }
+                    self.oplog.push_back(op);
+                }
+            } else {
+                self.oplog.extend(ops);
+            }
+
+            let publish = match self.auto_publish {
+                AutoPublish::EveryNOps(n) => self.oplog.len() - self.swap_index >= n,
+                AutoPublish::EveryDuration(d) => match self.last_publish {
+                    Some(last) => last.elapsed() >= d,
+                    None => false,
+                },
+                AutoPublish::Never => false,
+            };
+            if publish && self.has_pending_operations() && !self.poisoned {
+                self.publish();
+            }
+        }
+    }
+}
+
+/// A batch of operations that are exposed to readers by the same publish.
+///
+/// Created by [`WriteHandle::batch`]. Operations appended to the batch are held back until
+/// [`publish`](Self::publish) is called, and are discarded if the batch is dropped instead.
+#[must_use = "the operations in a batch are discarded unless it is published"]
+pub struct Batch<'w, T, O>
+where
+    T: Absorb<O>,
+{
+    handle: &'w mut WriteHandle<T, O>,
+    ops: Vec<O>,
+}
+
+impl<'w, T, O> fmt::Debug for Batch<'w, T, O>
+where
+    T: Absorb<O> + fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batch")
+            .field("handle", &self.handle)
+            .field("ops", &self.ops)
+            .finish()
+    }
+}
+
+impl<'w, T, O> Batch<'w, T, O>
+where
+    T: Absorb<O>,
+{
+    /// Add the given operation to the batch.
+    pub fn append(mut self, op: O) -> Self {
+        self.ops.push(op);
+        self
+    }
+
+    /// Append the operations in the batch to the operational log, and publish.
+    ///
+    /// The operations are appended all at once, so neither the [`AutoPublish`] policy nor the
+    /// limit on pending operations can split them across publishes. Any operations that were
+    /// already pending are published along with them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle is [poisoned](WriteHandle::is_poisoned), without appending any of the
+    /// operations in the batch. Use [`try_publish`](Self::try_publish) to get them back instead.
+    pub fn publish(self) -> &'w mut WriteHandle<T, O> {
+        match self.try_publish() {
+            Ok(handle) => handle,
+            Err(_) => panic!(
+                "a previous publish panicked; call WriteHandle::recover before publishing again"
+            ),
+        }
+    }
+
+    /// Append the operations in the batch to the operational log, and publish, unless the handle
+    /// is poisoned.
+    ///
+    /// This works like [`publish`](Self::publish), except that if the handle is
+    /// [poisoned](WriteHandle::is_poisoned), nothing is appended, and the operations in the batch
+    /// are returned along with the error. They can then be appended again once the handle has
+    /// been [recovered](WriteHandle::recover).
+    pub fn try_publish(self) -> Result<&'w mut WriteHandle<T, O>, (Poisoned, Vec<O>)> {
+        let Batch { handle, ops } = self;
+        if handle.poisoned {
+            return Err((Poisoned, ops));
+        }
+        if handle.first {
+            handle.extend(ops);
+        } else {
+            handle.oplog.extend(ops);
         }
+        Ok(handle.publish())
     }
 }
 
@@ -558,7 +1145,7 @@ struct CheckWriteHandleSend;
 
 #[cfg(test)]
 mod tests {
-    use crate::sync::{AtomicUsize, Mutex, Ordering};
+    use crate::sync::{AtomicUsize, CachePadded, Mutex, Ordering};
     use crate::Absorb;
     use slab::Slab;
     include!("./utilities.rs");
@@ -566,12 +1153,12 @@ mod tests {
     #[test]
     fn append_test() {
         let (mut w, _r) = crate::new::<i32, _>();
-        assert_eq!(w.first, true);
+        assert!(w.first);
         w.append(CounterAddOp(1));
         assert_eq!(w.oplog.len(), 0);
-        assert_eq!(w.first, true);
+        assert!(w.first);
         w.publish();
-        assert_eq!(w.first, false);
+        assert!(!w.first);
         w.append(CounterAddOp(2));
         w.append(CounterAddOp(3));
         assert_eq!(w.oplog.len(), 2);
@@ -619,6 +1206,43 @@ mod tests {
         assert_eq!(*w.take(), 2);
     }
 
+    #[test]
+    fn publish_incremental() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.append(CounterAddOp(1));
+        assert!(w.publish_incremental(1));
+        assert_eq!(*r.enter().unwrap(), 1);
+
+        w.append(CounterAddOp(1));
+        w.append(CounterAddOp(1));
+        w.append(CounterAddOp(1));
+        assert!(!w.publish_incremental(2));
+        assert_eq!(*r.enter().unwrap(), 1);
+
+        // appended after the incremental publish started, so left for the next one
+        w.append(CounterAddOp(10));
+        assert!(w.publish_incremental(2));
+        assert_eq!(*r.enter().unwrap(), 4);
+        assert!(w.has_pending_operations());
+
+        // three ops to catch up on from the last swap, then the pending one
+        assert!(!w.publish_incremental(3));
+        assert_eq!(*r.enter().unwrap(), 4);
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 14);
+        assert!(!w.has_pending_operations());
+        assert_eq!(*w.take(), 14);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one operation")]
+    fn publish_incremental_zero() {
+        let (mut w, _r) = crate::new::<i32, _>();
+        w.publish();
+        w.append(CounterAddOp(1));
+        w.publish_incremental(0);
+    }
+
     #[test]
     fn wait_test() {
         use std::sync::{Arc, Barrier};
@@ -633,12 +1257,18 @@ mod tests {
 
         // Case 2: If one of the reader is still reading(epoch is odd and count is same as in last_epoch)
         // and wait has been called.
-        let held_epoch = Arc::new(AtomicUsize::new(1));
+        let epoch = |n| {
+            Arc::new(crate::Epoch {
+                counter: CachePadded::new(AtomicUsize::new(n)),
+                label: None,
+            })
+        };
+        let held_epoch = epoch(1);
 
         w.last_epochs = vec![2, 2, 1];
         let mut epochs_slab = Slab::new();
-        epochs_slab.insert(Arc::new(AtomicUsize::new(2)));
-        epochs_slab.insert(Arc::new(AtomicUsize::new(2)));
+        epochs_slab.insert(epoch(2));
+        epochs_slab.insert(epoch(2));
         epochs_slab.insert(Arc::clone(&held_epoch));
 
         let barrier = Arc::new(Barrier::new(2));
@@ -647,7 +1277,7 @@ mod tests {
 
         // check writers waiting state before calling wait.
         let is_waiting_v = is_waiting.load(Ordering::Relaxed);
-        assert_eq!(false, is_waiting_v);
+        assert!(!is_waiting_v);
 
         let barrier2 = Arc::clone(&barrier);
         let test_epochs = Arc::new(Mutex::new(epochs_slab));
@@ -664,13 +1294,529 @@ mod tests {
             thread::yield_now();
         }
 
-        held_epoch.fetch_add(1, Ordering::SeqCst);
+        held_epoch.counter.fetch_add(1, Ordering::SeqCst);
 
         // join to make sure that wait must return after the progress/increment
         // of held_epoch.
         let _ = wait_handle.join();
     }
 
+    #[test]
+    fn custom_wait_strategy() {
+        use super::WaitStrategy;
+        use std::sync::mpsc;
+        use std::thread;
+
+        // loom's atomics cannot be constructed in a static.
+        static PAUSES: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        fn count(_: usize) {
+            PAUSES.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let (mut w, r) = crate::new::<i32, _>();
+        w.set_wait_strategy(WaitStrategy::Custom(count));
+        w.publish();
+
+        let (tx, rx) = mpsc::channel();
+        let reader = thread::spawn(move || {
+            let guard = r.enter().unwrap();
+            tx.send(()).unwrap();
+            // hold on to the read copy until the writer has had to wait for us
+            while PAUSES.load(Ordering::SeqCst) == 0 {
+                thread::yield_now();
+            }
+            drop(guard);
+        });
+        rx.recv().unwrap();
+
+        // the reader entered after the last swap, so it only holds up the second publish
+        w.append(CounterAddOp(1));
+        w.publish();
+        w.publish();
+        reader.join().unwrap();
+        assert_ne!(PAUSES.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn auto_publish() {
+        use super::AutoPublish;
+        use std::time::Duration;
+
+        let (mut w, r) = crate::new::<i32, _>();
+        w.set_auto_publish(AutoPublish::EveryNOps(2));
+        // writes before the first publish are not counted
+        w.append(CounterAddOp(1));
+        w.append(CounterAddOp(1));
+        assert_eq!(w.refreshes, 0);
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 2);
+
+        w.append(CounterAddOp(1));
+        assert_eq!(*r.enter().unwrap(), 2);
+        w.append(CounterAddOp(1));
+        assert_eq!(*r.enter().unwrap(), 4);
+        assert!(!w.has_pending_operations());
+
+        w.set_auto_publish(AutoPublish::EveryDuration(Duration::from_secs(0)));
+        w.append(CounterAddOp(1));
+        assert_eq!(*r.enter().unwrap(), 5);
+
+        w.set_auto_publish(AutoPublish::Never);
+        w.append(CounterAddOp(1));
+        w.append(CounterAddOp(1));
+        assert_eq!(*r.enter().unwrap(), 5);
+        assert!(w.has_pending_operations());
+    }
+
+    #[test]
+    fn absorb_batch() {
+        #[derive(Default)]
+        struct Summed {
+            total: i32,
+            batches: usize,
+        }
+
+        impl Absorb<CounterAddOp> for Summed {
+            fn absorb_first(&mut self, operation: &mut CounterAddOp, _: &Self) {
+                self.total += operation.0;
+            }
+
+            fn absorb_first_batch<'a, I>(&mut self, operations: I, _: &Self)
+            where
+                I: Iterator<Item = &'a mut CounterAddOp>,
+            {
+                self.total += operations.map(|op| op.0).sum::<i32>();
+                self.batches += 1;
+            }
+
+            fn absorb_second_batch<I>(&mut self, operations: I, _: &Self)
+            where
+                I: Iterator<Item = CounterAddOp>,
+            {
+                self.total += operations.map(|op| op.0).sum::<i32>();
+                self.batches += 1;
+            }
+
+            fn sync_with(&mut self, first: &Self) {
+                self.total = first.total;
+            }
+        }
+
+        let (mut w, r) = crate::new::<Summed, _>();
+        w.extend((1..=3).map(CounterAddOp));
+        w.publish();
+        assert_eq!(r.enter().unwrap().total, 6);
+        assert_eq!(r.enter().unwrap().batches, 1);
+
+        w.extend((1..=3).map(CounterAddOp));
+        w.publish();
+        assert_eq!(r.enter().unwrap().total, 12);
+        assert_eq!(r.enter().unwrap().batches, 1);
+        w.publish();
+        assert_eq!(r.enter().unwrap().total, 12);
+        // this copy also saw the operations appended before the first publish
+        assert_eq!(r.enter().unwrap().batches, 2);
+    }
+
+    #[test]
+    fn batch() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.batch().append(CounterAddOp(1)).publish();
+        assert_eq!(*r.enter().unwrap(), 1);
+
+        w.set_auto_publish(crate::AutoPublish::EveryNOps(1));
+        w.set_max_pending_ops(Some(1));
+        let refreshes = w.refreshes;
+        w.batch()
+            .append(CounterAddOp(1))
+            .append(CounterAddOp(1))
+            .append(CounterAddOp(1))
+            .publish();
+        assert_eq!(w.refreshes, refreshes + 1);
+        assert_eq!(*r.enter().unwrap(), 4);
+
+        // an unpublished batch is discarded
+        let _ = w.batch().append(CounterAddOp(10));
+        assert!(!w.has_pending_operations());
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 4);
+    }
+
+    #[test]
+    fn absorb_batch_partial() {
+        #[derive(Default)]
+        struct OneAtATime(i32);
+
+        impl Absorb<CounterAddOp> for OneAtATime {
+            fn absorb_first(&mut self, operation: &mut CounterAddOp, _: &Self) {
+                self.0 += operation.0;
+            }
+
+            fn absorb_first_batch<'a, I>(&mut self, mut operations: I, other: &Self)
+            where
+                I: Iterator<Item = &'a mut CounterAddOp>,
+            {
+                if let Some(operation) = operations.next() {
+                    self.absorb_first(operation, other);
+                }
+            }
+
+            fn absorb_second_batch<I>(&mut self, mut operations: I, other: &Self)
+            where
+                I: Iterator<Item = CounterAddOp>,
+            {
+                if let Some(operation) = operations.next() {
+                    self.absorb_second(operation, other);
+                }
+            }
+
+            fn sync_with(&mut self, first: &Self) {
+                self.0 = first.0;
+            }
+        }
+
+        // operations the batch methods leave behind are still absorbed
+        let (mut w, r) = crate::new::<OneAtATime, _>();
+        w.extend((0..3).map(|_| CounterAddOp(1)));
+        w.publish();
+        assert_eq!(r.enter().unwrap().0, 3);
+        w.extend((0..2).map(|_| CounterAddOp(1)));
+        w.publish();
+        assert_eq!(r.enter().unwrap().0, 5);
+        w.publish();
+        assert_eq!(r.enter().unwrap().0, 5);
+    }
+
+    #[test]
+    fn clock_only_read_on_timer() {
+        let (mut w, _r) = crate::new::<i32, _>();
+        w.publish();
+        assert!(w.last_publish.is_none());
+
+        w.set_auto_publish(crate::AutoPublish::EveryDuration(
+            std::time::Duration::from_secs(60),
+        ));
+        assert!(w.last_publish.is_some());
+        w.publish();
+        assert!(w.last_publish.is_some());
+
+        w.set_auto_publish(crate::AutoPublish::EveryNOps(10));
+        assert!(w.last_publish.is_none());
+        w.publish();
+        assert!(w.last_publish.is_none());
+    }
+
+    #[test]
+    fn max_pending_ops() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.set_max_pending_ops(Some(2));
+
+        // operations before the first publish are never pending
+        w.extend((0..3).map(|_| CounterAddOp(1)));
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 3);
+
+        w.append(CounterAddOp(1));
+        assert!(w.try_append(CounterAddOp(1)).is_ok());
+        assert_eq!(
+            w.try_append(CounterAddOp(10)).err().map(|op| op.0),
+            Some(10)
+        );
+        assert_eq!(*r.enter().unwrap(), 3);
+
+        // a full oplog is published to make room
+        w.append(CounterAddOp(1));
+        assert_eq!(*r.enter().unwrap(), 5);
+        w.extend((0..3).map(|_| CounterAddOp(1)));
+        assert_eq!(*r.enter().unwrap(), 7);
+        assert!(w.has_pending_operations());
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 9);
+    }
+
+    #[test]
+    fn reader_lag() {
+        let (mut w, r) = crate::new::<i32, _>();
+        assert_eq!(w.reader_lag(), 0);
+        w.publish();
+        assert_eq!(w.reader_lag(), 0);
+
+        // entered after the last swap, so does not hold up the next publish
+        let guard = r.enter();
+        w.publish();
+        assert_eq!(w.reader_lag(), 1);
+
+        drop(guard);
+        assert_eq!(w.reader_lag(), 0);
+    }
+
+    #[test]
+    fn enter_owned() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.append(CounterAddOp(1));
+        w.publish();
+
+        // the guard keeps reading even after the handle it came from is gone
+        let guard = r.enter_owned().unwrap();
+        drop(r);
+        w.append(CounterAddOp(1));
+        w.publish();
+        assert_eq!(*guard, 1);
+
+        // dropping the guard releases its epoch, so publishing no longer blocks
+        drop(guard);
+        w.publish();
+        w.publish();
+        assert_eq!(*w.enter().unwrap(), 2);
+    }
+
+    #[test]
+    fn scoped_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let (mut w, r) = crate::new::<i32, _>();
+        w.publish();
+        let factory = r.factory();
+        let readers = w.epochs.lock().unwrap().len();
+
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            factory.scoped(|r| {
+                let _guard = r.enter();
+                panic!("task failed while reading");
+            })
+        }));
+        assert!(res.is_err());
+
+        // the handle and its epoch are gone, so publishing does not wait on it
+        assert_eq!(w.epochs.lock().unwrap().len(), readers);
+        w.publish();
+        w.publish();
+        assert_eq!(w.reader_lag(), 0);
+    }
+
+    #[test]
+    fn snapshot() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.append(CounterAddOp(1));
+        w.publish();
+
+        // neither holds on to the read copy
+        let snapshot = r.snapshot();
+        let doubled = r.read_with(|&n| n * 2);
+        w.append(CounterAddOp(1));
+        w.publish();
+        w.publish();
+        assert_eq!(w.reader_lag(), 0);
+        assert_eq!(snapshot, Some(1));
+        assert_eq!(doubled, Some(2));
+        assert_eq!(r.snapshot(), Some(2));
+    }
+
+    #[test]
+    fn map_split() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.append(CounterAddOp(1));
+        w.publish();
+
+        let (a, b) = crate::ReadGuard::map_split(r.enter().unwrap(), |t| (t, t));
+        w.append(CounterAddOp(1));
+        w.publish();
+        assert_eq!(w.reader_lag(), 1);
+
+        // either half on its own keeps the epoch held, so the next publish would still wait
+        drop(a);
+        assert_eq!(w.reader_lag(), 1);
+        assert_eq!(*b, 1);
+
+        drop(b);
+        assert_eq!(w.reader_lag(), 0);
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 2);
+        // the handle checks that all of its guards are gone when dropped
+        drop(r);
+    }
+
+    #[test]
+    // relies on overflow checks to make absorb panic
+    #[cfg(debug_assertions)]
+    fn poisoned() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let (mut w, r) = crate::new::<i32, _>();
+        w.append(CounterAddOp(1));
+        w.publish();
+        w.append(CounterAddOp(1));
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 2);
+
+        // overflows in absorb_first
+        w.append(CounterAddOp(i32::MAX));
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            w.publish();
+        }));
+        assert!(res.is_err());
+        assert!(w.is_poisoned());
+        assert_eq!(w.try_publish().err(), Some(crate::Poisoned));
+
+        // appending does not trigger a publish that would panic
+        w.set_auto_publish(crate::AutoPublish::EveryNOps(1));
+        w.append(CounterAddOp(1));
+        w.set_auto_publish(crate::AutoPublish::Never);
+
+        // readers are unaffected, and can still come and go
+        assert_eq!(*r.enter().unwrap(), 2);
+        let r2 = r.clone();
+        assert_eq!(*r2.enter().unwrap(), 2);
+        drop(r2);
+
+        w.recover(0);
+        assert!(!w.is_poisoned());
+        assert!(!w.has_pending_operations());
+        w.append(CounterAddOp(3));
+        assert!(w.try_publish().is_ok());
+        assert_eq!(*r.enter().unwrap(), 5);
+        w.publish();
+        assert_eq!(*r.enter().unwrap(), 5);
+        assert_eq!(*w.take(), 5);
+
+        // a poisoned handle can still be dropped
+        let (mut w, r) = crate::new::<i32, _>();
+        w.publish();
+        w.append(CounterAddOp(i32::MAX));
+        w.append(CounterAddOp(1));
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            w.publish();
+        }));
+        assert!(res.is_err());
+        drop(w);
+        assert!(r.enter().is_none());
+    }
+
+    #[test]
+    fn poisoned_in_absorb_second() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        #[derive(Default)]
+        struct SecondFails(i32);
+
+        impl Absorb<CounterAddOp> for SecondFails {
+            fn absorb_first(&mut self, operation: &mut CounterAddOp, _: &Self) {
+                self.0 += operation.0;
+            }
+
+            fn absorb_second(&mut self, operation: CounterAddOp, _: &Self) {
+                assert!(operation.0 >= 0, "negative operation");
+                self.0 += operation.0;
+            }
+
+            fn sync_with(&mut self, first: &Self) {
+                self.0 = first.0;
+            }
+        }
+
+        let (mut w, r) = crate::new::<SecondFails, _>();
+        w.publish();
+        w.append(CounterAddOp(-1));
+        w.append(CounterAddOp(1));
+        w.publish();
+        assert_eq!(r.enter().unwrap().0, 0);
+
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            w.publish();
+        }));
+        assert!(res.is_err());
+        assert!(w.is_poisoned());
+
+        // the operations drained before the panic must not throw off the pending count
+        w.set_auto_publish(crate::AutoPublish::EveryNOps(1));
+        w.set_max_pending_ops(Some(1));
+        w.append(CounterAddOp(1));
+        assert!(w.try_append(CounterAddOp(1)).is_err());
+        assert_eq!(r.enter().unwrap().0, 0);
+
+        // a batch is handed back rather than appended
+        let (_, ops) = w
+            .batch()
+            .append(CounterAddOp(5))
+            .append(CounterAddOp(5))
+            .try_publish()
+            .err()
+            .unwrap();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(w.oplog.len(), 1);
+
+        w.recover(SecondFails(0));
+        w.append(CounterAddOp(2));
+        assert_eq!(r.enter().unwrap().0, 2);
+    }
+
+    #[test]
+    fn wait_diagnostics() {
+        use std::sync::{mpsc, Arc, Mutex};
+        use std::thread;
+        use std::time::Duration;
+
+        let (mut w, r) = crate::new::<i32, _>();
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let reports2 = Arc::clone(&reports);
+        w.set_wait_diagnostics(Duration::from_secs(0), move |blocking| {
+            reports2.lock().unwrap().push(
+                blocking
+                    .iter()
+                    .map(|b| b.label().map(String::from))
+                    .collect::<Vec<_>>(),
+            );
+        });
+        w.publish();
+
+        let (tx, rx) = mpsc::channel();
+        let factory = r.factory();
+        let reports2 = Arc::clone(&reports);
+        let reader = thread::spawn(move || {
+            let r = factory.labeled("stuck");
+            let guard = r.enter().unwrap();
+            tx.send(()).unwrap();
+            // hold on to the read copy until the writer has told on us
+            while reports2.lock().unwrap().is_empty() {
+                thread::yield_now();
+            }
+            drop(guard);
+        });
+        rx.recv().unwrap();
+
+        // the reader entered after the last swap, so it only holds up the second publish
+        w.publish();
+        w.publish();
+        reader.join().unwrap();
+        assert_eq!(
+            *reports.lock().unwrap(),
+            vec![vec![Some(String::from("stuck"))]]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "held by the calling thread")]
+    fn publish_while_reading() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.publish();
+        let _guard = r.enter().unwrap();
+        // the reader entered after the last swap, so only the second publish has to wait for it
+        w.publish();
+        w.publish();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "held by the calling thread")]
+    fn publish_while_reading_owned() {
+        let (mut w, r) = crate::new::<i32, _>();
+        w.publish();
+        let _guard = r.enter_owned().unwrap();
+        w.publish();
+        w.publish();
+    }
+
     #[test]
     fn flush_noblock() {
         let (mut w, r) = crate::new::<i32, _>();